@@ -1,6 +1,7 @@
 mod full_path;
 mod kind;
 mod obj;
+mod path_index;
 mod setget;
 mod source_tree;
 
@@ -12,8 +13,10 @@ use std::{
     rc::{Rc, Weak},
 };
 
-use anyhow::{bail, Result};
+use anyhow::{anyhow, bail, Result};
+pub use full_path::*;
 pub use kind::*;
+pub use path_index::*;
 pub use source_tree::*;
 use tap::Pipe;
 
@@ -145,6 +148,182 @@ impl PBXFSReference {
             }
         })
     }
+
+    /// Recursively resolve a `/`-separated path relative to this group, e.g.
+    /// `"Source/Models/Log.swift"`, descending into subgroups one segment at
+    /// a time.
+    ///
+    /// A child's path segment is its `path()`, falling back to its `name()`
+    /// only when `path()` is absent — the same precedence [`PathIndex`] keys
+    /// its cache by, so `find_by_path` and a `PathIndex` built from the same
+    /// tree always agree.
+    ///
+    /// NOTE: This will return None if self is file
+    pub fn find_by_path(&self, relative: &str) -> Option<Rc<RefCell<PBXFSReference>>> {
+        if self.is_file() {
+            return None;
+        }
+
+        let (segment, rest) = match relative.split_once('/') {
+            Some((segment, rest)) => (segment, Some(rest)),
+            None => (relative, None),
+        };
+
+        let child = self.children().into_iter().find(|c| {
+            let c = c.borrow();
+            c.path().or(c.name()).map(String::as_str) == Some(segment)
+        })?;
+
+        match rest {
+            Some(rest) => child.borrow().find_by_path(rest),
+            None => Some(child),
+        }
+    }
+
+    /// Create a new `PBXFileReference` and add it as a child of this group.
+    ///
+    /// `this` should be a weak pointer to `self`, used to wire up the new
+    /// file's `parent`, following the same convention as [`Self::set_parent`].
+    ///
+    /// # Errors
+    /// Returns an error if `self` is a file, since files cannot have children.
+    pub fn add_file<S: Into<String>>(
+        &mut self,
+        this: Weak<RefCell<Self>>,
+        name: S,
+        source_tree: PBXSourceTree,
+        last_known_file_type: Option<String>,
+    ) -> Result<Rc<RefCell<PBXFSReference>>> {
+        if self.is_file() {
+            bail!("cannot add a file to a PBXFSReference that is itself a file");
+        }
+
+        let name = name.into();
+        let mut reference = PBXFSReference {
+            source_tree: Some(source_tree),
+            path: Some(name.clone()),
+            name: Some(name),
+            last_known_file_type,
+            kind: PBXFSReferenceKind::File,
+            objects: self.objects.clone(),
+            ..Default::default()
+        };
+        reference.set_parent(this);
+
+        self.insert_child(reference)
+    }
+
+    /// Create a new, empty `PBXGroup` and add it as a child of this group.
+    ///
+    /// `this` should be a weak pointer to `self`, used to wire up the new
+    /// group's `parent`, following the same convention as [`Self::set_parent`].
+    ///
+    /// # Errors
+    /// Returns an error if `self` is a file, since files cannot have children.
+    pub fn create_group<S: Into<String>>(
+        &mut self,
+        this: Weak<RefCell<Self>>,
+        name: S,
+    ) -> Result<Rc<RefCell<PBXFSReference>>> {
+        if self.is_file() {
+            bail!("cannot add a group to a PBXFSReference that is itself a file");
+        }
+
+        let name = name.into();
+        let mut group = PBXFSReference {
+            path: Some(name.clone()),
+            name: Some(name),
+            kind: PBXFSReferenceKind::Group,
+            children_references: Some(HashSet::new()),
+            objects: self.objects.clone(),
+            ..Default::default()
+        };
+        group.set_parent(this);
+
+        self.insert_child(group)
+    }
+
+    /// Remove the child with the given `uuid` from this group.
+    ///
+    /// Prunes `uuid`, and recursively every one of its own descendants, from
+    /// both `children_references` and the shared objects collection, so no
+    /// orphaned or dangling reference is left behind.
+    ///
+    /// # Errors
+    /// Returns an error if `self` is a file, or if `uuid` is not a child of
+    /// this group.
+    pub fn remove_child(&mut self, uuid: &str) -> Result<()> {
+        if self.is_file() {
+            bail!("cannot remove a child from a PBXFSReference that is itself a file");
+        }
+
+        let removed = self
+            .children_references
+            .as_mut()
+            .map(|children| children.remove(uuid))
+            .unwrap_or(false);
+
+        if !removed {
+            bail!("`{uuid}` is not a child of this group");
+        }
+
+        let objects = self.objects.upgrade().expect("Objects to valid reference");
+        let child = objects
+            .borrow()
+            .get(uuid)
+            .and_then(|o| o.as_pbxfs_reference())
+            .cloned();
+
+        if let Some(child) = child {
+            child.borrow().remove_descendants(&self.objects);
+        }
+        objects.borrow_mut().remove(uuid);
+
+        Ok(())
+    }
+
+    /// Recursively prune every descendant of `self` from `objects`.
+    ///
+    /// `self`'s own entry is left for the caller to remove, since it is the
+    /// caller who knows `self`'s uuid.
+    fn remove_descendants(&self, objects: &WeakPBXObjectCollection) {
+        let Some(children) = &self.children_references else {
+            return;
+        };
+
+        let collection = objects.upgrade().expect("Objects to valid reference");
+        for child_uuid in children {
+            let child = collection
+                .borrow()
+                .get(child_uuid)
+                .and_then(|o| o.as_pbxfs_reference())
+                .cloned();
+
+            if let Some(child) = child {
+                child.borrow().remove_descendants(objects);
+            }
+            collection.borrow_mut().remove(child_uuid);
+        }
+    }
+
+    /// Allocate `reference` in the shared objects collection and register it
+    /// as a child of `self`.
+    fn insert_child(&mut self, reference: PBXFSReference) -> Result<Rc<RefCell<PBXFSReference>>> {
+        let objects = self.objects.upgrade().expect("Objects to valid reference");
+        let uuid = objects.borrow_mut().insert_pbxfs_reference(reference);
+        let inserted = objects
+            .borrow()
+            .get(&uuid)
+            .and_then(|o| o.as_pbxfs_reference())
+            .cloned()
+            .ok_or_else(|| anyhow!("failed to retrieve `{uuid}` after inserting it"))?;
+
+        self.children_references
+            .get_or_insert_with(HashSet::new)
+            .insert(uuid);
+
+        Ok(inserted)
+    }
 }
 
 impl Eq for PBXFSReference {}
@@ -212,4 +391,263 @@ mod tests {
         let file = source_group.get_file("Log.swift");
         assert!(file.is_some())
     }
+    #[test]
+    fn find_by_path() {
+        use crate::pbxproj::test_demo_file;
+        let project = test_demo_file!(demo1);
+        let main_group = project
+            .objects()
+            .projects()
+            .first()
+            .unwrap()
+            .1
+            .borrow()
+            .main_group();
+
+        let main_group = main_group.borrow();
+        let file = main_group.find_by_path("Source/Log.swift");
+        assert!(file.is_some());
+        assert!(main_group.find_by_path("Source/DoesNotExist.swift").is_none());
+    }
+    #[test]
+    fn find_by_path_and_path_index_agree_when_name_and_path_differ() {
+        use crate::pbxproj::test_demo_file;
+        use super::{PBXSourceTree, PathIndex};
+        use std::rc::Rc;
+
+        let project = test_demo_file!(demo1);
+        let source_group = project
+            .objects()
+            .get_group_by_name_or_path("Source")
+            .unwrap()
+            .1;
+
+        let file = source_group
+            .borrow_mut()
+            .add_file(
+                Rc::downgrade(&source_group),
+                "File.swift",
+                PBXSourceTree::Group,
+                Some("sourcecode.swift".to_string()),
+            )
+            .unwrap();
+        // Give the node a display `name` distinct from its on-disk `path`,
+        // as a real `.pbxproj` can: both functions must resolve the same
+        // segment (`path()`, falling back to `name()`) so a `PathIndex` and
+        // `find_by_path` never disagree on the same tree.
+        file.borrow_mut().name = Some("DisplayName".to_string());
+
+        let index = PathIndex::build(&source_group);
+
+        assert!(source_group.borrow().find_by_path("File.swift").is_some());
+        assert!(index.get("Source/File.swift").is_some());
+
+        assert!(source_group.borrow().find_by_path("DisplayName").is_none());
+        assert!(index.get("Source/DisplayName").is_none());
+    }
+    #[test]
+    fn path_index_finds_file_under_unnamed_root() {
+        use crate::pbxproj::test_demo_file;
+        use super::PathIndex;
+
+        let project = test_demo_file!(demo1);
+        let main_group = project
+            .objects()
+            .projects()
+            .first()
+            .unwrap()
+            .1
+            .borrow()
+            .main_group();
+
+        // The root `main_group` has no `name`/`path` of its own; make sure
+        // the index still descends into its children.
+        assert!(main_group.borrow().name().is_none());
+        assert!(main_group.borrow().path().is_none());
+
+        let index = PathIndex::build(&main_group);
+        assert!(index.get("Source/Log.swift").is_some());
+        assert!(index.get("Source/DoesNotExist.swift").is_none());
+    }
+    #[test]
+    fn path_index_rebuild_picks_up_new_files() {
+        use crate::pbxproj::test_demo_file;
+        use super::{PBXSourceTree, PathIndex};
+        use std::rc::Rc;
+
+        let project = test_demo_file!(demo1);
+        let main_group = project
+            .objects()
+            .projects()
+            .first()
+            .unwrap()
+            .1
+            .borrow()
+            .main_group();
+
+        let mut index = PathIndex::build(&main_group);
+        assert!(index.get("Source/NewFile.swift").is_none());
+
+        let source_group = main_group.borrow().get_subgroup("Source").unwrap();
+        source_group
+            .borrow_mut()
+            .add_file(
+                Rc::downgrade(&source_group),
+                "NewFile.swift",
+                PBXSourceTree::Group,
+                Some("sourcecode.swift".to_string()),
+            )
+            .unwrap();
+
+        index.invalidate();
+        index.rebuild(&main_group);
+        assert!(index.get("Source/NewFile.swift").is_some());
+    }
+    #[test]
+    fn add_file_inserts_a_file_child() {
+        use crate::pbxproj::test_demo_file;
+        use super::PBXSourceTree;
+        use std::rc::Rc;
+
+        let project = test_demo_file!(demo1);
+        let source_group = project
+            .objects()
+            .get_group_by_name_or_path("Source")
+            .unwrap()
+            .1;
+
+        let file = source_group
+            .borrow_mut()
+            .add_file(
+                Rc::downgrade(&source_group),
+                "NewFile.swift",
+                PBXSourceTree::Group,
+                Some("sourcecode.swift".to_string()),
+            )
+            .unwrap();
+
+        assert!(file.borrow().is_file());
+        assert!(source_group.borrow().get_file("NewFile.swift").is_some());
+        assert_eq!(
+            file.borrow().parent().unwrap().borrow().children_references(),
+            source_group.borrow().children_references()
+        );
+    }
+    #[test]
+    fn add_file_on_a_file_errors() {
+        use crate::pbxproj::test_demo_file;
+        use super::PBXSourceTree;
+        use std::rc::Rc;
+
+        let project = test_demo_file!(demo1);
+        let source_group = project
+            .objects()
+            .get_group_by_name_or_path("Source")
+            .unwrap()
+            .1;
+        let file = source_group.borrow().get_file("Log.swift").unwrap();
+
+        let result = file.borrow_mut().add_file(
+            Rc::downgrade(&file),
+            "Nested.swift",
+            PBXSourceTree::Group,
+            None,
+        );
+
+        assert!(result.is_err());
+    }
+    #[test]
+    fn create_group_inserts_a_subgroup() {
+        use crate::pbxproj::test_demo_file;
+        use std::rc::Rc;
+
+        let project = test_demo_file!(demo1);
+        let source_group = project
+            .objects()
+            .get_group_by_name_or_path("Source")
+            .unwrap()
+            .1;
+
+        let group = source_group
+            .borrow_mut()
+            .create_group(Rc::downgrade(&source_group), "Models")
+            .unwrap();
+
+        assert!(group.borrow().is_group());
+        assert!(source_group.borrow().get_subgroup("Models").is_some());
+    }
+    #[test]
+    fn create_group_on_a_file_errors() {
+        use crate::pbxproj::test_demo_file;
+        use std::rc::Rc;
+
+        let project = test_demo_file!(demo1);
+        let source_group = project
+            .objects()
+            .get_group_by_name_or_path("Source")
+            .unwrap()
+            .1;
+        let file = source_group.borrow().get_file("Log.swift").unwrap();
+
+        let result = file.borrow_mut().create_group(Rc::downgrade(&file), "Nested");
+
+        assert!(result.is_err());
+    }
+    #[test]
+    fn remove_child_prunes_the_child_and_its_descendants() {
+        use crate::pbxproj::test_demo_file;
+        use std::rc::Rc;
+
+        let project = test_demo_file!(demo1);
+        let source_group = project
+            .objects()
+            .get_group_by_name_or_path("Source")
+            .unwrap()
+            .1;
+
+        source_group
+            .borrow_mut()
+            .create_group(Rc::downgrade(&source_group), "Models")
+            .unwrap();
+        let (group_uuid, group) = project
+            .objects()
+            .get_group_by_name_or_path("Models")
+            .unwrap();
+        group
+            .borrow_mut()
+            .add_file(
+                Rc::downgrade(&group),
+                "Log.swift",
+                super::PBXSourceTree::Group,
+                None,
+            )
+            .unwrap();
+        let file_uuid = group
+            .borrow()
+            .children_references()
+            .iter()
+            .next()
+            .cloned()
+            .unwrap();
+
+        source_group.borrow_mut().remove_child(&group_uuid).unwrap();
+
+        assert!(source_group.borrow().get_subgroup("Models").is_none());
+        assert!(project.objects().get(&group_uuid).is_none());
+        assert!(project.objects().get(&file_uuid).is_none());
+    }
+    #[test]
+    fn remove_child_on_a_file_errors() {
+        use crate::pbxproj::test_demo_file;
+
+        let project = test_demo_file!(demo1);
+        let source_group = project
+            .objects()
+            .get_group_by_name_or_path("Source")
+            .unwrap()
+            .1;
+        let file = source_group.borrow().get_file("Log.swift").unwrap();
+
+        assert!(file.borrow_mut().remove_child("anything").is_err());
+    }
 }
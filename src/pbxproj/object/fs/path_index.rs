@@ -0,0 +1,73 @@
+use super::*;
+use std::collections::HashMap;
+
+/// Pre-built index mapping a group-relative path, e.g.
+/// `"Source/Models/Log.swift"`, to the reference found at that path.
+///
+/// Built once via [`PathIndex::build`] after parsing, this turns repeated
+/// nested lookups into a single hash lookup instead of the scan of each
+/// group's children along the path that [`PBXFSReference::find_by_path`]
+/// does on every call.
+#[derive(Debug, Default)]
+pub struct PathIndex {
+    by_path: HashMap<String, Rc<RefCell<PBXFSReference>>>,
+}
+
+impl PathIndex {
+    /// Walk `root` depth-first and index every node by the path accumulated
+    /// along the way, using each node's `path()` or, failing that, its
+    /// `name()` — the same precedence [`PBXFSReference::find_by_path`]
+    /// resolves a segment against.
+    ///
+    /// When `root` itself has a `path()` or `name()`, it is indexed too and
+    /// its segment starts every key (building from the `Source` group gives
+    /// `"Source/Log.swift"`); an unnamed root adds no segment.
+    #[must_use]
+    pub fn build(root: &Rc<RefCell<PBXFSReference>>) -> Self {
+        let mut index = Self::default();
+        index.walk(root, String::new());
+        index
+    }
+
+    /// Look up the reference at `path`, e.g. `"Source/Models/Log.swift"`.
+    pub fn get(&self, path: &str) -> Option<Rc<RefCell<PBXFSReference>>> {
+        self.by_path.get(path).cloned()
+    }
+
+    /// Discard the current index without recomputing it.
+    ///
+    /// Call this after the mutation API (`add_file`, `create_group`,
+    /// `remove_child`) changes the tree, then call [`Self::rebuild`] before
+    /// the next lookup.
+    pub fn invalidate(&mut self) {
+        self.by_path.clear();
+    }
+
+    /// Recompute the index from `root` from scratch.
+    pub fn rebuild(&mut self, root: &Rc<RefCell<PBXFSReference>>) {
+        self.by_path.clear();
+        self.walk(root, String::new());
+    }
+
+    fn walk(&mut self, node: &Rc<RefCell<PBXFSReference>>, prefix: String) {
+        let borrowed = node.borrow();
+        let segment = borrowed.path().or(borrowed.name()).cloned();
+
+        // The root `main_group` is typically unnamed and has no `path`; it
+        // only exists to hold children, so it has no indexable path of its
+        // own. Still recurse into its children with the unchanged prefix.
+        let path = match &segment {
+            Some(segment) if prefix.is_empty() => segment.clone(),
+            Some(segment) => format!("{prefix}/{segment}"),
+            None => prefix,
+        };
+
+        for child in borrowed.children() {
+            self.walk(&child, path.clone());
+        }
+
+        if segment.is_some() {
+            self.by_path.insert(path, node.clone());
+        }
+    }
+}